@@ -0,0 +1,4 @@
+//! No library surface of its own: this crate exists only to compile and run
+//! the fixtures under `test/projects/vivafolio-data-examples/` as real
+//! `[[example]]` targets, so the backlog fixtures are genuine, checked code
+//! rather than illustrative text.
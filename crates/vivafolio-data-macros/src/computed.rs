@@ -0,0 +1,79 @@
+use heck::ToSnekCase;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    LitStr, Token,
+};
+use vivafolio_data::computed;
+
+/// `"column name" => "lua formula"`, as written inside the `{ ... }` block of
+/// a `vivafolio_data_column!` invocation.
+struct ColumnDef {
+    name: LitStr,
+    formula: LitStr,
+}
+
+impl Parse for ColumnDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let formula: LitStr = input.parse()?;
+        Ok(ColumnDef { name, formula })
+    }
+}
+
+/// `vivafolio_data_column!("table_name", { "col" => "formula", ... });`
+struct Invocation {
+    table_name: LitStr,
+    defs: Punctuated<ColumnDef, Token![,]>,
+}
+
+impl Parse for Invocation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let table_name: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let braces;
+        braced!(braces in input);
+        let defs = braces.parse_terminated(ColumnDef::parse, Token![,])?;
+        Ok(Invocation { table_name, defs })
+    }
+}
+
+pub fn expand(input: TokenStream) -> TokenStream {
+    let invocation = parse_macro_input!(input as Invocation);
+
+    if invocation.defs.is_empty() {
+        return syn::Error::new_spanned(&invocation.table_name, "vivafolio_data_column! needs at least one column")
+            .to_compile_error()
+            .into();
+    }
+
+    let defs: Vec<(String, String)> =
+        invocation.defs.iter().map(|def| (def.name.value(), def.formula.value())).collect();
+    let borrowed_defs: Vec<(&str, &str)> =
+        defs.iter().map(|(name, formula)| (name.as_str(), formula.as_str())).collect();
+
+    // Validate for real, right now: Lua syntax errors and dependency cycles
+    // between columns are compile errors, not a panic the first time the
+    // generated function runs.
+    if let Err(error) = computed::validate_defs(&borrowed_defs) {
+        return syn::Error::new_spanned(&invocation.table_name, format!("invalid computed columns: {error}"))
+            .to_compile_error()
+            .into();
+    }
+
+    let fn_name = format_ident!("{}_with_computed_columns", invocation.table_name.value().to_snek_case());
+    let names = defs.iter().map(|(name, _)| name);
+    let formulas = defs.iter().map(|(_, formula)| formula);
+
+    quote! {
+        pub fn #fn_name(table: ::vivafolio_data::Table) -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+            ::vivafolio_data::computed::apply(table, &[#((#names, #formulas)),*])
+        }
+    }
+    .into()
+}
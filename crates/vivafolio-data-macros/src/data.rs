@@ -0,0 +1,208 @@
+use heck::ToSnekCase;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use sqlparser::{dialect::GenericDialect, parser::Parser as SqlParser};
+use std::path::Path;
+use syn::{parse_macro_input, LitStr};
+use vivafolio_data::formats::{self, DataFormat};
+
+use crate::input::Invocation;
+
+pub fn expand(input: TokenStream) -> TokenStream {
+    let invocation = parse_macro_input!(input as Invocation);
+
+    let table_name = invocation.name.value();
+    let fn_name = format_ident!("{}", table_name.to_snek_case());
+
+    let sources: Vec<&'static str> = [
+        invocation.payload.is_some().then_some("an inline literal"),
+        invocation.kv("from_file").is_some().then_some("from_file"),
+        invocation.kv("from_url").is_some().then_some("from_url"),
+        invocation.kv("from_sql").is_some().then_some("from_sql"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if sources.len() != 1 {
+        return syn::Error::new_spanned(
+            &invocation.name,
+            format!(
+                "vivafolio_data! needs exactly one data source (inline literal, from_file, from_url, \
+                 or from_sql), found {}: {}",
+                sources.len(),
+                if sources.is_empty() { "none".to_string() } else { sources.join(", ") }
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if invocation.kv("compression").is_some() && invocation.payload.is_none() {
+        return syn::Error::new_spanned(
+            &invocation.name,
+            "compression = \"...\" is only valid alongside an inline literal",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let result = if let Some(payload) = &invocation.payload {
+        expand_inline(&table_name, &fn_name, &invocation, payload)
+    } else if let Some(path) = invocation.kv("from_file") {
+        expand_from_file(&table_name, &fn_name, path)
+    } else if let Some(url) = invocation.kv("from_url") {
+        expand_from_url(&table_name, &fn_name, url)
+    } else {
+        expand_from_sql(&table_name, &fn_name, invocation.kv("from_sql").expect("checked above"))
+    };
+
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_inline(
+    table_name: &str,
+    fn_name: &proc_macro2::Ident,
+    invocation: &Invocation,
+    payload: &LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let compression = invocation.kv("compression");
+    if let Some(lit) = compression {
+        if lit.value() != "zstd" {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("unknown compression {:?} (only \"zstd\" is supported)", lit.value()),
+            ));
+        }
+    }
+
+    // Decompress up front (if compressed) so sniffing/parsing always run
+    // against the same plaintext the runtime will eventually see.
+    let plaintext = match compression {
+        Some(_) => vivafolio_data::compression::decompress_from_base64(&payload.value())
+            .map_err(|source| syn::Error::new_spanned(payload, format!("invalid zstd payload: {source}")))?,
+        None => payload.value(),
+    };
+
+    let format = resolve_format(invocation, &plaintext)?;
+
+    // Validate the literal for real, right now, so a malformed table is a
+    // compile error pointing at the literal rather than a runtime panic.
+    if let Err(source) = formats::parse_format(table_name, format, &plaintext) {
+        return Err(syn::Error::new_spanned(payload, format!("invalid {} table: {source}", format.name())));
+    }
+
+    let format_variant = format_variant_tokens(format);
+    Ok(match compression {
+        Some(_) => quote! {
+            pub fn #fn_name() -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+                let plaintext = ::vivafolio_data::compression::decompress_from_base64(#payload)?;
+                ::vivafolio_data::formats::parse_format(#table_name, #format_variant, &plaintext)
+            }
+        },
+        None => quote! {
+            pub fn #fn_name() -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+                ::vivafolio_data::formats::parse_format(#table_name, #format_variant, #payload)
+            }
+        },
+    })
+}
+
+fn expand_from_file(
+    table_name: &str,
+    fn_name: &proc_macro2::Ident,
+    path_lit: &LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let relative = path_lit.value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|error| syn::Error::new_spanned(path_lit, format!("CARGO_MANIFEST_DIR unset: {error}")))?;
+    let absolute = Path::new(&manifest_dir).join(&relative);
+
+    if !absolute.exists() {
+        return Err(syn::Error::new_spanned(
+            path_lit,
+            format!("from_file = {relative:?} does not exist (resolved to {})", absolute.display()),
+        ));
+    }
+    // Validate that the existing file actually parses, same as an inline literal would.
+    let content = std::fs::read_to_string(&absolute)
+        .map_err(|error| syn::Error::new_spanned(path_lit, format!("failed to read {relative:?}: {error}")))?;
+    if let Err(source) = formats::parse_auto(table_name, &content) {
+        return Err(syn::Error::new_spanned(path_lit, format!("invalid data in {relative:?}: {source}")));
+    }
+
+    Ok(quote! {
+        pub fn #fn_name() -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+            ::vivafolio_data::external::load_file(
+                #table_name,
+                ::std::path::Path::new(::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), "/", #relative)),
+            )
+        }
+    })
+}
+
+fn expand_from_url(
+    table_name: &str,
+    fn_name: &proc_macro2::Ident,
+    url_lit: &LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let url = url_lit.value();
+    if url.trim().is_empty() {
+        return Err(syn::Error::new_spanned(url_lit, "from_url must not be empty"));
+    }
+
+    // A relative `file://` URL is anchored to CARGO_MANIFEST_DIR, the same
+    // rule `from_file` follows, so both forms are portable across checkouts.
+    let url_expr = match url.strip_prefix("file://") {
+        Some(rest) if !Path::new(rest).is_absolute() => {
+            quote! { ::std::concat!("file://", ::std::env!("CARGO_MANIFEST_DIR"), "/", #rest) }
+        }
+        _ => quote! { #url },
+    };
+
+    Ok(quote! {
+        pub fn #fn_name() -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+            ::vivafolio_data::external::load_url(#table_name, #url_expr)
+        }
+    })
+}
+
+fn expand_from_sql(
+    table_name: &str,
+    fn_name: &proc_macro2::Ident,
+    query_lit: &LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let query = query_lit.value();
+    if let Err(error) = SqlParser::parse_sql(&GenericDialect {}, &query) {
+        return Err(syn::Error::new_spanned(query_lit, format!("from_sql does not parse: {error}")));
+    }
+
+    Ok(quote! {
+        pub fn #fn_name(
+            connection: &::vivafolio_data::external::Connection,
+        ) -> ::vivafolio_data::Result<::vivafolio_data::Table> {
+            ::vivafolio_data::external::load_sql(#table_name, connection, #query)
+        }
+    })
+}
+
+fn resolve_format(invocation: &Invocation, plaintext: &str) -> syn::Result<DataFormat> {
+    match invocation.kv("format") {
+        Some(lit) => DataFormat::parse_name(&lit.value())
+            .map_err(|error| syn::Error::new_spanned(lit, error.to_string())),
+        None => Ok(formats::sniff_format(plaintext)),
+    }
+}
+
+fn format_variant_tokens(format: DataFormat) -> proc_macro2::TokenStream {
+    match format {
+        DataFormat::Csv => quote!(::vivafolio_data::formats::DataFormat::Csv),
+        DataFormat::Tsv => quote!(::vivafolio_data::formats::DataFormat::Tsv),
+        DataFormat::Json => quote!(::vivafolio_data::formats::DataFormat::Json),
+        DataFormat::Yaml => quote!(::vivafolio_data::formats::DataFormat::Yaml),
+        DataFormat::Markdown => quote!(::vivafolio_data::formats::DataFormat::Markdown),
+    }
+}
@@ -0,0 +1,48 @@
+//! Shared argument grammar for the `vivafolio_*!` macros:
+//! `"name", key = "value", ..., "optional trailing literal"`.
+
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitStr, Token,
+};
+
+pub struct Invocation {
+    pub name: LitStr,
+    pub kvs: Vec<(Ident, LitStr)>,
+    pub payload: Option<LitStr>,
+}
+
+impl Invocation {
+    pub fn kv(&self, key: &str) -> Option<&LitStr> {
+        self.kvs.iter().find(|(ident, _)| ident == key).map(|(_, value)| value)
+    }
+}
+
+impl Parse for Invocation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        let mut kvs = Vec::new();
+        let mut payload = None;
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            if input.peek(LitStr) {
+                let lit: LitStr = input.parse()?;
+                if payload.is_some() {
+                    return Err(syn::Error::new_spanned(&lit, "only one inline literal payload is allowed"));
+                }
+                payload = Some(lit);
+            } else {
+                let key: Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                kvs.push((key, value));
+            }
+        }
+
+        Ok(Invocation { name, kvs, payload })
+    }
+}
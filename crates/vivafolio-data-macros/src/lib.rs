@@ -0,0 +1,91 @@
+//! Proc macros for embedding [`vivafolio_data::Table`] data directly in
+//! source files. Every macro here does real work at expansion time (parsing
+//! the literal/validating the reference through `vivafolio-data` itself) so
+//! a malformed table is a compile error, not a runtime surprise; the
+//! generated code then re-does the same, now-proven-valid, work at runtime
+//! because the parsed [`vivafolio_data::Table`] can't be spliced into the
+//! token stream as a literal.
+
+mod computed;
+mod data;
+mod input;
+mod rdf;
+mod report;
+
+use proc_macro::TokenStream;
+
+/// `vivafolio_data!("name", [format = "...",] "literal")` or
+/// `vivafolio_data!("name", from_file|from_url|from_sql = "...")`.
+///
+/// An inline literal may also carry `compression = "zstd"`, in which case
+/// the literal is a base64(zstd(...)) blob of the plaintext table (see
+/// [`vivafolio_data::compression`]) rather than the plaintext itself — use
+/// this to keep a source file manageable when a table has thousands of
+/// rows. Small tables should stay plain literals; compress an existing one
+/// in place with the `vivafolio-data-compress` tool.
+///
+/// Expands to a `pub fn <name>() -> vivafolio_data::Result<vivafolio_data::Table>`
+/// (or, for `from_sql`, a function additionally taking a
+/// `&vivafolio_data::external::Connection`) that loads the table at
+/// runtime. Whichever source is used is also validated right here at
+/// expansion time: an inline literal or `from_file` path is parsed with the
+/// same code the runtime will use (a compressed literal is decompressed
+/// first, so the same validation runs against the same plaintext either
+/// way), a `from_file` path is checked to exist, and a `from_sql` query is
+/// checked to parse as SQL — so a malformed table is a compile error
+/// pointing at the offending argument, not a panic the first time the
+/// generated function is called.
+#[proc_macro]
+pub fn vivafolio_data(input: TokenStream) -> TokenStream {
+    data::expand(input)
+}
+
+/// `vivafolio_report!("template.yaml")`.
+///
+/// Reads and validates the YAML report template (see
+/// `vivafolio_data::report::Template`) at expansion time and embeds it via
+/// `include_str!`, so both a missing file and a malformed template are
+/// compile errors. Expands to a `pub fn render_<template stem>(table,
+/// params, format: vivafolio_data::report::RenderFormat) ->
+/// vivafolio_data::Result<String>` that walks the template's sections over
+/// `table`, substituting `$F(column)` per row and `$P{name}` from `params`,
+/// and renders the result as plain text or HTML per `format`. `column_header`
+/// widths are honored as column padding (text) or `<th style="width: ...">`
+/// (HTML); a `page_size` repeats the column header every N rows and inserts
+/// a page break between pages.
+#[proc_macro]
+pub fn vivafolio_report(input: TokenStream) -> TokenStream {
+    report::expand(input)
+}
+
+/// `vivafolio_data_column!("table_name", { "column" => "lua formula", ... });`
+///
+/// Each formula is Lua, evaluated per row by an embedded, pure-Rust Lua
+/// interpreter (see [`vivafolio_data::computed`]), with `$F(column)`
+/// substituted for that row's value of `column` (a base column or an
+/// earlier entry in this same declaration) and `today()`/`days_between(a,
+/// b)` available for date arithmetic. Formulas are compiled and
+/// topologically ordered right here at expansion time, so a Lua syntax
+/// error or a dependency cycle between columns is a compile error.
+///
+/// Expands to a `pub fn <table_name>_with_computed_columns(table:
+/// vivafolio_data::Table) -> vivafolio_data::Result<vivafolio_data::Table>`
+/// that appends the computed columns, in declaration order, to `table`.
+#[proc_macro]
+pub fn vivafolio_data_column(input: TokenStream) -> TokenStream {
+    computed::expand(input)
+}
+
+/// `vivafolio_rdf_export!("table_name", context = "context.jsonld", format = "turtle" | "json-ld")`.
+///
+/// Reads and validates the JSON-LD [`vivafolio_data::rdf::Context`] at
+/// expansion time (a missing file or malformed context is a compile error,
+/// same as an unknown `format`) and embeds it via `include_str!`. Expands to
+/// a `pub fn export_<table_name>_rdf(table: &vivafolio_data::Table) ->
+/// vivafolio_data::Result<String>` that converts each row to a subject
+/// (identified by the context's `subject_column`, under `subject_prefix`)
+/// and one triple per mapped column, serialized as Turtle or JSON-LD.
+#[proc_macro]
+pub fn vivafolio_rdf_export(input: TokenStream) -> TokenStream {
+    rdf::expand(input)
+}
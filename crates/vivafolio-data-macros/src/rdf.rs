@@ -0,0 +1,80 @@
+use heck::ToSnekCase;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use std::path::Path;
+use syn::{parse_macro_input, LitStr};
+use vivafolio_data::rdf::{self, ExportFormat};
+
+use crate::input::Invocation;
+
+pub fn expand(input: TokenStream) -> TokenStream {
+    let invocation = parse_macro_input!(input as Invocation);
+
+    let table_name = invocation.name.value();
+    let context_lit = match invocation.kv("context") {
+        Some(lit) => lit,
+        None => {
+            return syn::Error::new_spanned(&invocation.name, "vivafolio_rdf_export! needs a context = \"...\" path")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let format_lit = match invocation.kv("format") {
+        Some(lit) => lit,
+        None => {
+            return syn::Error::new_spanned(&invocation.name, "vivafolio_rdf_export! needs a format = \"...\" name")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    match expand_checked(&table_name, context_lit, format_lit) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_checked(
+    table_name: &str,
+    context_lit: &LitStr,
+    format_lit: &LitStr,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let format_name = format_lit.value();
+    ExportFormat::parse_name(&format_name)
+        .map_err(|error| syn::Error::new_spanned(format_lit, error.to_string()))?;
+
+    let relative = context_lit.value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|error| syn::Error::new_spanned(context_lit, format!("CARGO_MANIFEST_DIR unset: {error}")))?;
+    let absolute = Path::new(&manifest_dir).join(&relative);
+
+    if !absolute.exists() {
+        return Err(syn::Error::new_spanned(
+            context_lit,
+            format!("context = {relative:?} does not exist (resolved to {})", absolute.display()),
+        ));
+    }
+    let content = std::fs::read_to_string(&absolute)
+        .map_err(|error| syn::Error::new_spanned(context_lit, format!("failed to read {relative:?}: {error}")))?;
+    // Validate the context parses, right here, the same way the generated
+    // function's runtime call to `rdf::export` will re-parse it.
+    if let Err(error) = rdf::parse_context(&content) {
+        return Err(syn::Error::new_spanned(context_lit, format!("invalid RDF context in {relative:?}: {error}")));
+    }
+
+    let fn_name = format_ident!("export_{}_rdf", table_name.to_snek_case());
+    let format_variant = match ExportFormat::parse_name(&format_name).expect("validated above") {
+        ExportFormat::Turtle => quote!(::vivafolio_data::rdf::ExportFormat::Turtle),
+        ExportFormat::JsonLd => quote!(::vivafolio_data::rdf::ExportFormat::JsonLd),
+    };
+
+    Ok(quote! {
+        pub fn #fn_name(table: &::vivafolio_data::Table) -> ::vivafolio_data::Result<String> {
+            ::vivafolio_data::rdf::export(
+                table,
+                ::std::include_str!(::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), "/", #relative)),
+                #format_variant,
+            )
+        }
+    })
+}
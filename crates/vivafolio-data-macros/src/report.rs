@@ -0,0 +1,58 @@
+use heck::ToSnekCase;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use std::path::Path;
+use syn::{parse_macro_input, LitStr};
+
+pub fn expand(input: TokenStream) -> TokenStream {
+    let path_lit = parse_macro_input!(input as LitStr);
+    let relative = path_lit.value();
+
+    let manifest_dir = match std::env::var("CARGO_MANIFEST_DIR") {
+        Ok(dir) => dir,
+        Err(error) => {
+            return syn::Error::new_spanned(&path_lit, format!("CARGO_MANIFEST_DIR unset: {error}"))
+                .to_compile_error()
+                .into();
+        }
+    };
+    let absolute = Path::new(&manifest_dir).join(&relative);
+
+    let yaml = match std::fs::read_to_string(&absolute) {
+        Ok(yaml) => yaml,
+        Err(error) => {
+            return syn::Error::new_spanned(
+                &path_lit,
+                format!("failed to read report template {relative:?}: {error}"),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    if let Err(error) = vivafolio_data::report::parse_template(&yaml) {
+        return syn::Error::new_spanned(&path_lit, format!("invalid report template: {error}"))
+            .to_compile_error()
+            .into();
+    }
+
+    let stem = Path::new(&relative).file_stem().and_then(|stem| stem.to_str()).unwrap_or(&relative);
+    let fn_name = format_ident!("render_{}", stem.to_snek_case());
+
+    let expanded = quote! {
+        pub fn #fn_name(
+            table: &::vivafolio_data::Table,
+            params: &::std::collections::HashMap<String, String>,
+            format: ::vivafolio_data::report::RenderFormat,
+        ) -> ::vivafolio_data::Result<String> {
+            ::vivafolio_data::report::render_report(
+                ::std::include_str!(::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), "/", #relative)),
+                table,
+                params,
+                format,
+            )
+        }
+    };
+
+    expanded.into()
+}
@@ -0,0 +1,83 @@
+//! Implementation behind the `vivafolio-data-compress` binary: rewrites an
+//! existing `vivafolio_data!("name", "plaintext literal")` invocation in
+//! place into its `compression = "zstd"` form, so a table that has grown too
+//! large to keep as readable plaintext can be shrunk without hand-editing
+//! the base64 blob.
+
+use std::sync::LazyLock;
+
+static PLAIN_INVOCATION: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r##"(?s)vivafolio_data!\(\s*"(?P<name>[^"]+)"\s*,\s*r#"(?P<payload>.*?)"#\s*\)"##)
+        .expect("valid regex")
+});
+
+static COMPRESSED_INVOCATION: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r##"(?s)vivafolio_data!\(\s*"(?P<name>[^"]+)"\s*,\s*compression\s*=\s*"zstd"\s*,\s*r#"[^"]*"#\s*\)"##,
+    )
+    .expect("valid regex")
+});
+
+/// Finds the inline `vivafolio_data!("table_name", "...")` invocation for
+/// `table_name` in `source` and rewrites it to carry a zstd-compressed,
+/// base64-encoded payload instead of the plaintext literal. Returns the
+/// rewritten source.
+pub fn compress_table_in_place(source: &str, table_name: &str) -> Result<String, String> {
+    if COMPRESSED_INVOCATION.captures_iter(source).any(|captures| &captures["name"] == table_name) {
+        return Err(format!("table {table_name:?} is already compressed"));
+    }
+
+    let mut found = false;
+    let rewritten = PLAIN_INVOCATION.replace_all(source, |captures: &regex::Captures| {
+        if found || &captures["name"] != table_name {
+            return captures[0].to_string();
+        }
+        found = true;
+
+        let plaintext = captures["payload"].trim();
+        let encoded = vivafolio_data::compression::compress_to_base64(plaintext)
+            .expect("compressing a plaintext literal cannot fail");
+        format!("vivafolio_data!(\"{table_name}\", compression = \"zstd\", r#\"\n{encoded}\n\"#)")
+    });
+
+    if !found {
+        return Err(format!("no inline vivafolio_data!({table_name:?}, \"...\") literal found"));
+    }
+    Ok(rewritten.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compresses_the_named_table_literal() {
+        let source = "vivafolio_data!(\"small\", r#\"\nId,Value\n1,alpha\n\"#);\n";
+        let rewritten = compress_table_in_place(source, "small").unwrap();
+        assert!(rewritten.contains("compression = \"zstd\""));
+        assert!(!rewritten.contains("Id,Value"));
+
+        let payload = rewritten.split("r#\"").nth(1).unwrap().split("\"#").next().unwrap().trim();
+        assert_eq!(vivafolio_data::compression::decompress_from_base64(payload).unwrap(), "Id,Value\n1,alpha");
+    }
+
+    #[test]
+    fn leaves_other_tables_untouched() {
+        let source = "vivafolio_data!(\"a\", r#\"\nX\n1\n\"#);\nvivafolio_data!(\"b\", r#\"\nY\n2\n\"#);\n";
+        let rewritten = compress_table_in_place(source, "b").unwrap();
+        assert!(rewritten.contains("vivafolio_data!(\"a\", r#\"\nX\n1\n\"#);"));
+        assert!(rewritten.contains("\"b\", compression = \"zstd\""));
+    }
+
+    #[test]
+    fn errors_when_table_is_missing() {
+        let source = "vivafolio_data!(\"a\", r#\"\nX\n1\n\"#);\n";
+        assert!(compress_table_in_place(source, "missing").is_err());
+    }
+
+    #[test]
+    fn errors_when_table_is_already_compressed() {
+        let source = "vivafolio_data!(\"a\", compression = \"zstd\", r#\"\nKLUv\n\"#);\n";
+        assert!(compress_table_in_place(source, "a").is_err());
+    }
+}
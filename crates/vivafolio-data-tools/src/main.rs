@@ -0,0 +1,39 @@
+//! `vivafolio-data-compress <file.rs> <table-name>`
+//!
+//! Rewrites the named `vivafolio_data!` table literal in `<file.rs>` in
+//! place to carry a zstd-compressed, base64-encoded payload instead of
+//! plaintext.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (Some(path), Some(table_name)) = (args.next(), args.next()) else {
+        eprintln!("usage: vivafolio-data-compress <file.rs> <table-name>");
+        return ExitCode::FAILURE;
+    };
+
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("failed to read {path:?}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rewritten = match vivafolio_data_tools::compress_table_in_place(&source, &table_name) {
+        Ok(rewritten) => rewritten,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(error) = std::fs::write(&path, rewritten) {
+        eprintln!("failed to write {path:?}: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("compressed table {table_name:?} in {path}");
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,56 @@
+//! The base64(zstd(...)) codec backing `vivafolio_data!`'s `compression =
+//! "zstd"` source: a large table is stored compressed so it doesn't bloat
+//! the source file as plaintext, and is transparently decompressed back into
+//! the same plaintext the uncompressed-literal path would parse.
+
+use crate::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::Cursor;
+
+/// Favors a small blob over encoding speed: these payloads are written once
+/// (by the compress-in-place tool) and decoded many times.
+const ZSTD_LEVEL: i32 = 19;
+
+/// Compresses `plaintext` with zstd and base64-encodes the result, producing
+/// the payload a `vivafolio_data!(..., compression = "zstd", "...")` literal
+/// carries.
+pub fn compress_to_base64(plaintext: &str) -> Result<String> {
+    let compressed = zstd::encode_all(Cursor::new(plaintext.as_bytes()), ZSTD_LEVEL).map_err(Error::Zstd)?;
+    Ok(STANDARD.encode(compressed))
+}
+
+/// The inverse of [`compress_to_base64`]: base64-decodes and zstd-decompresses
+/// `encoded` back into the original plaintext.
+pub fn decompress_from_base64(encoded: &str) -> Result<String> {
+    let compressed = STANDARD
+        .decode(encoded.trim())
+        .map_err(|error| Error::Malformed { what: "base64 payload", detail: error.to_string() })?;
+    let decompressed = zstd::decode_all(Cursor::new(compressed)).map_err(Error::Zstd)?;
+    String::from_utf8(decompressed)
+        .map_err(|error| Error::Malformed { what: "decompressed payload", detail: error.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_compression_and_decompression() {
+        let plaintext = "Id,Value\n1,alpha\n2,beta\n";
+        let encoded = compress_to_base64(plaintext).unwrap();
+        assert_eq!(decompress_from_base64(&encoded).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        let error = decompress_from_base64("not base64!!").unwrap_err();
+        assert!(matches!(error, Error::Malformed { what: "base64 payload", .. }));
+    }
+
+    #[test]
+    fn rejects_base64_that_is_not_zstd() {
+        let encoded = STANDARD.encode(b"plain bytes, not a zstd frame");
+        let error = decompress_from_base64(&encoded).unwrap_err();
+        assert!(matches!(error, Error::Zstd(_)));
+    }
+}
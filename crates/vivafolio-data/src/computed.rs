@@ -0,0 +1,374 @@
+//! Computed ("formula") columns for [`Table`], backed by the pure-Rust,
+//! stackless [`hematita`] Lua interpreter: each computed column's formula is
+//! compiled once and then evaluated per row, with the row's other columns
+//! (base or already-computed) bound as Lua globals, plus a small
+//! date-arithmetic standard library (`today()`, `days_between(a, b)`).
+//!
+//! Computed columns may reference each other via `$F(other column)`;
+//! [`apply`]/[`ComputedColumns`] topologically order them first and reject
+//! any cycle.
+
+use crate::{Error, Result, Table, Value};
+use chrono::NaiveDate;
+use hematita::{
+    ast::{
+        lexer::Lexer,
+        parser::{parse_block, TokenIterator},
+    },
+    compiler::compile_block,
+    lua_lib::{self, table_to_vector},
+    lua_tuple,
+    vm::{
+        value::{Function, Nillable::NonNil, Table as LuaTable, Value as LuaValue},
+        VirtualMachine,
+    },
+};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::LazyLock,
+};
+
+static FIELD_PATTERN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\$F\(([^)]+)\)").expect("valid regex"));
+
+/// Evaluates every `defs` formula (`(column name, Lua expression)`) against
+/// `table`, topologically ordering defs that reference each other via
+/// `$F(other computed column)`, and appends the results as new columns.
+pub fn apply(table: Table, defs: &[(&str, &str)]) -> Result<Table> {
+    ComputedColumns::new(defs)?.refresh(&table)
+}
+
+/// Compiles and topologically orders `defs` without evaluating them against
+/// any table. Used by `vivafolio_data_column!` to validate a computed-column
+/// declaration (Lua syntax errors, unknown cycles) at macro-expansion time,
+/// the same way [`crate::formats::parse_format`] backs `vivafolio_data!`.
+pub fn validate_defs(defs: &[(&str, &str)]) -> Result<()> {
+    ComputedColumns::new(defs)?;
+    Ok(())
+}
+
+/// An incremental evaluator: formulas are compiled once (at
+/// [`ComputedColumns::new`]) and a row is only re-evaluated if its
+/// underlying cell values have changed since the last
+/// [`ComputedColumns::refresh`].
+pub struct ComputedColumns {
+    defs: Vec<(String, String)>,
+    order: Vec<usize>,
+    compiled: Vec<CompiledFormula>,
+    row_fingerprints: HashMap<usize, u64>,
+    row_values: HashMap<usize, Vec<Value>>,
+}
+
+/// A formula compiled to Lua bytecode once, plus the column names its
+/// `$F(...)` references were rewritten to refer to (as the synthetic Lua
+/// identifiers `__f0`, `__f1`, ... in order of first appearance, since
+/// column names aren't generally valid Lua identifiers).
+struct CompiledFormula {
+    function: Function<'static>,
+    fields: Vec<String>,
+}
+
+impl ComputedColumns {
+    pub fn new(defs: &[(&str, &str)]) -> Result<Self> {
+        let defs: Vec<(String, String)> =
+            defs.iter().map(|(name, formula)| (name.to_string(), formula.to_string())).collect();
+        let order = topological_order(&defs)?;
+        let mut compiled = Vec::with_capacity(defs.len());
+        for (name, formula) in &defs {
+            compiled.push(
+                compile_formula(formula)
+                    .map_err(|detail| Error::Lua { formula: format!("{name}: {formula}"), detail })?,
+            );
+        }
+
+        Ok(ComputedColumns { defs, order, compiled, row_fingerprints: HashMap::new(), row_values: HashMap::new() })
+    }
+
+    /// Re-evaluates against `base`, reusing cached per-row results for rows
+    /// whose inputs are unchanged, and returns `base` with the computed
+    /// columns appended (in declaration order).
+    pub fn refresh(&mut self, base: &Table) -> Result<Table> {
+        // Filled in topological order so later formulas can see earlier
+        // computed columns by name.
+        let mut computed: HashMap<String, Vec<Value>> = HashMap::new();
+
+        for &def_index in &self.order {
+            let mut values = Vec::with_capacity(base.rows.len());
+
+            for row_index in 0..base.rows.len() {
+                let fingerprint = row_fingerprint(base, row_index, &computed);
+                let unchanged = self.row_fingerprints.get(&row_index) == Some(&fingerprint);
+                let cached = if unchanged {
+                    self.row_values.get(&row_index).and_then(|cols| cols.get(def_index).cloned())
+                } else {
+                    None
+                };
+
+                let value = match cached {
+                    Some(value) => value,
+                    None => {
+                        let env = row_env(base, row_index, &computed);
+                        evaluate(&self.compiled[def_index], &env).map_err(|detail| Error::Lua {
+                            formula: self.defs[def_index].1.clone(),
+                            detail,
+                        })?
+                    }
+                };
+
+                self.row_fingerprints.insert(row_index, fingerprint);
+                self.row_values.entry(row_index).or_insert_with(|| vec![Value::Null; self.defs.len()])[def_index] =
+                    value.clone();
+                values.push(value);
+            }
+
+            computed.insert(self.defs[def_index].0.clone(), values);
+        }
+
+        let mut result = base.clone();
+        for (name, _) in &self.defs {
+            let values = computed.remove(name).expect("every def evaluated above");
+            result = result.with_column(name.clone(), values);
+        }
+        Ok(result)
+    }
+}
+
+/// A cheap hash of everything a row's computed columns can see: its base
+/// cells, plus any already-computed column values for that row. Used to
+/// skip re-evaluating rows whose inputs haven't changed.
+fn row_fingerprint(table: &Table, row_index: usize, computed: &HashMap<String, Vec<Value>>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for cell in &table.rows[row_index] {
+        cell.display().hash(&mut hasher);
+    }
+    let mut names: Vec<&String> = computed.keys().collect();
+    names.sort();
+    for name in names {
+        name.hash(&mut hasher);
+        computed[name][row_index].display().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn row_env(table: &Table, row_index: usize, computed: &HashMap<String, Vec<Value>>) -> HashMap<String, Value> {
+    let mut env = HashMap::new();
+    for (column, cell) in table.columns.iter().zip(&table.rows[row_index]) {
+        env.insert(column.clone(), cell.clone());
+    }
+    for (name, values) in computed {
+        env.insert(name.clone(), values[row_index].clone());
+    }
+    env
+}
+
+/// Kahn's algorithm over the dependency graph formed by `$F(other computed
+/// column)` references between defs; returns an evaluation order, or an
+/// error naming a column stuck in a cycle.
+fn topological_order(defs: &[(String, String)]) -> Result<Vec<usize>> {
+    let pattern = &*FIELD_PATTERN;
+    let names: Vec<&str> = defs.iter().map(|(name, _)| name.as_str()).collect();
+
+    // `depends_on[i]` = indices of defs that formula `i` references.
+    let depends_on: Vec<Vec<usize>> = defs
+        .iter()
+        .map(|(_, formula)| {
+            pattern
+                .captures_iter(formula)
+                .filter_map(|captures| names.iter().position(|name| *name == &captures[1]))
+                .collect()
+        })
+        .collect();
+
+    let mut in_degree = vec![0usize; defs.len()];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); defs.len()];
+    for (i, deps) in depends_on.iter().enumerate() {
+        in_degree[i] = deps.len();
+        for &dep in deps {
+            dependents[dep].push(i);
+        }
+    }
+
+    let mut ready: Vec<usize> = (0..defs.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(defs.len());
+    while let Some(index) = ready.pop() {
+        order.push(index);
+        for &dependent in &dependents[index] {
+            in_degree[dependent] -= 1;
+            if in_degree[dependent] == 0 {
+                ready.push(dependent);
+            }
+        }
+    }
+
+    if order.len() != defs.len() {
+        let stuck = (0..defs.len()).find(|index| !order.contains(index)).expect("cycle exists");
+        return Err(Error::ComputedCycle(defs[stuck].0.clone()));
+    }
+
+    Ok(order)
+}
+
+/// Compiles a formula to a reusable [`Function`]. Column names referenced
+/// via `$F(name)` generally aren't valid Lua identifiers (they may contain
+/// spaces), so each distinct reference is rewritten to a synthetic `__fN`
+/// global in order of first appearance; [`CompiledFormula::fields`] records
+/// which original column name each `__fN` stands for.
+fn compile_formula(formula: &str) -> std::result::Result<CompiledFormula, String> {
+    let mut fields = Vec::new();
+    let rewritten = FIELD_PATTERN.replace_all(formula, |captures: &regex::Captures| {
+        let name = captures[1].to_string();
+        let index = match fields.iter().position(|existing| *existing == name) {
+            Some(index) => index,
+            None => {
+                fields.push(name);
+                fields.len() - 1
+            }
+        };
+        format!("__f{index}")
+    });
+
+    let source = format!("return {rewritten}");
+    let lexer = Lexer { source: source.chars().peekable() }.peekable();
+    let parsed =
+        parse_block(&mut TokenIterator(lexer)).map_err(|error| format!("failed to parse formula: {error:?}"))?;
+    Ok(CompiledFormula { function: compile_block(&parsed).into(), fields })
+}
+
+fn evaluate(compiled: &CompiledFormula, env: &HashMap<String, Value>) -> std::result::Result<Value, String> {
+    let globals = lua_lib::standard_globals();
+    {
+        let mut data = globals.data.lock().unwrap();
+        data.insert(LuaValue::new_string("today"), LuaValue::NativeFunction(&today));
+        data.insert(LuaValue::new_string("days_between"), LuaValue::NativeFunction(&days_between));
+        for (index, name) in compiled.fields.iter().enumerate() {
+            let value = env.get(name).ok_or_else(|| format!("formula references unknown field {name:?}"))?;
+            data.insert(LuaValue::new_string(format!("__f{index}")), to_lua_value(name, value)?);
+        }
+    }
+
+    let vm = VirtualMachine::new(globals);
+    let result = vm.execute(&compiled.function, lua_tuple![].arc())?;
+    let value = result.data.lock().unwrap().get(&LuaValue::Integer(1)).cloned();
+    Ok(from_lua_value(value.as_ref()))
+}
+
+fn today(
+    _arguments: std::sync::Arc<LuaTable<'static>>,
+    _vm: &VirtualMachine<'static>,
+) -> std::result::Result<std::sync::Arc<LuaTable<'static>>, String> {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+    let days = chrono::Utc::now().date_naive().signed_duration_since(epoch).num_days();
+    Ok(lua_tuple![days].arc())
+}
+
+fn days_between(
+    arguments: std::sync::Arc<LuaTable<'static>>,
+    _vm: &VirtualMachine<'static>,
+) -> std::result::Result<std::sync::Arc<LuaTable<'static>>, String> {
+    let values = table_to_vector(&arguments);
+    let (a, b) = match (values.first(), values.get(1)) {
+        (Some(NonNil(a)), Some(NonNil(b))) => (a, b),
+        _ => return Err("days_between expects two date arguments".to_string()),
+    };
+
+    let a_days = lua_value_to_epoch_days(a)?;
+    let b_days = lua_value_to_epoch_days(b)?;
+    Ok(lua_tuple![b_days - a_days].arc())
+}
+
+fn lua_value_to_epoch_days(value: &LuaValue<'static>) -> std::result::Result<i64, String> {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+    match value {
+        LuaValue::Integer(epoch_day) => Ok(*epoch_day),
+        LuaValue::String(text) => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .map(|date| date.signed_duration_since(epoch).num_days())
+            .map_err(|error| format!("{text:?} is not a YYYY-MM-DD date: {error}")),
+        other => Err(format!("cannot interpret a {} as a date", other.type_name())),
+    }
+}
+
+/// Converts a bound `$F(name)` value to Lua. `hematita` has no float type
+/// (only [`LuaValue::Integer`]), so a [`Value::Number`] can't be represented
+/// as a Lua number, and converting it to a string instead would make
+/// arithmetic on it fail with an opaque "unknown binary operation" VM error;
+/// reject it here instead, by the column's name, with a clear message.
+fn to_lua_value(name: &str, value: &Value) -> std::result::Result<LuaValue<'static>, String> {
+    match value {
+        Value::Text(text) => Ok(LuaValue::new_string(text)),
+        Value::Integer(i) => Ok(LuaValue::Integer(*i)),
+        Value::Number(_) => {
+            Err(format!("column {name:?} holds a non-integer number, which computed columns don't support (no float type)"))
+        }
+        Value::Bool(b) => Ok(LuaValue::Boolean(*b)),
+        Value::Null => Ok(LuaValue::new_string("")),
+    }
+}
+
+fn from_lua_value(value: Option<&LuaValue<'static>>) -> Value {
+    match value {
+        None => Value::Null,
+        Some(LuaValue::Integer(i)) => Value::Integer(*i),
+        Some(LuaValue::Boolean(b)) => Value::Bool(*b),
+        Some(LuaValue::String(s)) => Value::Text(s.to_string()),
+        Some(other) => Value::Text(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tasks_table() -> Table {
+        Table::new(
+            "project_tasks",
+            vec!["Task Name".into(), "Due Date".into()],
+            vec![
+                vec![Value::Text("Implement authentication".into()), Value::Text("2000-01-02".into())],
+                vec![Value::Text("Design database schema".into()), Value::Text("2000-01-01".into())],
+            ],
+        )
+    }
+
+    #[test]
+    fn evaluates_a_single_formula() {
+        let table = apply(tasks_table(), &[("Days Since Epoch", "days_between(0, $F(Due Date))")]).unwrap();
+        assert_eq!(table.cell(0, "Days Since Epoch"), Some(&Value::Integer(10958)));
+        assert_eq!(table.cell(1, "Days Since Epoch"), Some(&Value::Integer(10957)));
+    }
+
+    #[test]
+    fn chained_computed_columns_see_each_other() {
+        let table = apply(
+            tasks_table(),
+            &[
+                ("Days Since Epoch", "days_between(0, $F(Due Date))"),
+                ("Is Recent", "$F(Days Since Epoch) > 10957"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(table.cell(0, "Is Recent"), Some(&Value::Bool(true)));
+        assert_eq!(table.cell(1, "Is Recent"), Some(&Value::Bool(false)));
+    }
+
+    #[test]
+    fn cycle_is_rejected() {
+        let error = apply(tasks_table(), &[("A", "$F(B)"), ("B", "$F(A)")]).unwrap_err();
+        assert!(matches!(error, Error::ComputedCycle(_)));
+    }
+
+    #[test]
+    fn number_columns_are_rejected_with_a_clear_error() {
+        let table = Table::new("products", vec!["Price".into()], vec![vec![Value::Number(2.5)]]);
+        let error = apply(table, &[("Total", "$F(Price) * 2")]).unwrap_err();
+        assert!(matches!(error, Error::Lua { detail, .. } if detail.contains("Price") && detail.contains("float")));
+    }
+
+    #[test]
+    fn refresh_skips_unchanged_rows() {
+        let mut engine = ComputedColumns::new(&[("Days Since Epoch", "days_between(0, $F(Due Date))")]).unwrap();
+        let first = engine.refresh(&tasks_table()).unwrap();
+        let second = engine.refresh(&tasks_table()).unwrap();
+        assert_eq!(first.rows, second.rows);
+    }
+}
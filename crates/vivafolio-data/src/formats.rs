@@ -0,0 +1,267 @@
+//! Parsers for every inline format `vivafolio_data!` accepts, plus the
+//! auto-detection sniffer used when no explicit `format = "..."` is given.
+//! Every parser here normalizes its input into the same [`Table`] model.
+
+use crate::{Error, Result, Table, Value};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+    Csv,
+    Tsv,
+    Json,
+    Yaml,
+    Markdown,
+}
+
+impl DataFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            DataFormat::Csv => "csv",
+            DataFormat::Tsv => "tsv",
+            DataFormat::Json => "json",
+            DataFormat::Yaml => "yaml",
+            DataFormat::Markdown => "markdown",
+        }
+    }
+
+    /// Parses the explicit `format = "..."` macro argument.
+    pub fn parse_name(name: &str) -> Result<Self> {
+        match name {
+            "csv" => Ok(DataFormat::Csv),
+            "tsv" => Ok(DataFormat::Tsv),
+            "json" => Ok(DataFormat::Json),
+            "yaml" => Ok(DataFormat::Yaml),
+            "markdown" => Ok(DataFormat::Markdown),
+            other => Err(Error::UnknownFormat(other.to_string())),
+        }
+    }
+}
+
+/// Sniffs the format of an inline literal from its leading characters, per
+/// the `vivafolio_data!` auto-detection rule: `[`/`{` -> JSON, leading `|`
+/// -> Markdown table, a tab on the first data line -> TSV, a leading `- `
+/// -> YAML sequence, otherwise CSV.
+pub fn sniff_format(input: &str) -> DataFormat {
+    let trimmed = input.trim_start();
+    match trimmed.chars().next() {
+        Some('[') | Some('{') => DataFormat::Json,
+        Some('|') => DataFormat::Markdown,
+        _ if trimmed.starts_with("- ") => DataFormat::Yaml,
+        _ => {
+            let first_line = trimmed.lines().next().unwrap_or_default();
+            if first_line.contains('\t') {
+                DataFormat::Tsv
+            } else {
+                DataFormat::Csv
+            }
+        }
+    }
+}
+
+/// Parses `input` using the given `format`, producing a [`Table`] named
+/// `name`.
+pub fn parse_format(name: &str, format: DataFormat, input: &str) -> Result<Table> {
+    match format {
+        DataFormat::Csv => parse_delimited(name, input, b','),
+        DataFormat::Tsv => parse_delimited(name, input, b'\t'),
+        DataFormat::Json => parse_json(name, input),
+        DataFormat::Yaml => parse_yaml(name, input),
+        DataFormat::Markdown => parse_markdown(name, input),
+    }
+}
+
+/// Sniffs and parses `input`, per the `vivafolio_data!` auto-detection rule.
+pub fn parse_auto(name: &str, input: &str) -> Result<Table> {
+    parse_format(name, sniff_format(input), input)
+}
+
+fn wrap<E: std::error::Error + Send + Sync + 'static>(
+    table: &str,
+    format: DataFormat,
+) -> impl FnOnce(E) -> Error + '_ {
+    move |source| Error::Parse { table: table.to_string(), format: format.name(), source: Box::new(source) }
+}
+
+fn parse_delimited(name: &str, input: &str, delimiter: u8) -> Result<Table> {
+    let format = if delimiter == b'\t' { DataFormat::Tsv } else { DataFormat::Csv };
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .has_headers(true)
+        .from_reader(input.trim().as_bytes());
+
+    let columns: Vec<String> =
+        reader.headers().map_err(wrap(name, format))?.iter().map(str::to_string).collect();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(wrap(name, format))?;
+        rows.push(record.iter().map(|field| Value::Text(field.to_string())).collect());
+    }
+
+    Ok(Table::new(name, columns, rows))
+}
+
+fn parse_json(name: &str, input: &str) -> Result<Table> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(input.trim()).map_err(wrap(name, DataFormat::Json))?;
+    let objects = parsed.as_array().ok_or_else(|| Error::Malformed {
+        what: "json table",
+        detail: "expected a top-level JSON array of objects".to_string(),
+    })?;
+    table_from_objects(name, objects, DataFormat::Json)
+}
+
+fn parse_yaml(name: &str, input: &str) -> Result<Table> {
+    let parsed: Vec<serde_yaml::Value> =
+        serde_yaml::from_str(input.trim()).map_err(wrap(name, DataFormat::Yaml))?;
+    let objects: Vec<serde_json::Value> = parsed
+        .into_iter()
+        .map(|value| serde_json::to_value(value).map_err(wrap(name, DataFormat::Yaml)))
+        .collect::<Result<_>>()?;
+    table_from_objects(name, &objects, DataFormat::Yaml)
+}
+
+/// Shared normalization for the two "array of objects" formats: column order
+/// follows first-appearance across all rows, and a row missing a column gets
+/// [`Value::Null`] rather than shifting later columns.
+fn table_from_objects(name: &str, objects: &[serde_json::Value], format: DataFormat) -> Result<Table> {
+    let mut columns = Vec::new();
+    let mut seen = BTreeSet::new();
+    for object in objects {
+        let map = object.as_object().ok_or_else(|| Error::Malformed {
+            what: "table row",
+            detail: "expected each row to be an object".to_string(),
+        })?;
+        for key in map.keys() {
+            if seen.insert(key.clone()) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    let rows = objects
+        .iter()
+        .map(|object| {
+            let map = object.as_object().expect("validated above");
+            columns.iter().map(|column| json_to_value(map.get(column))).collect()
+        })
+        .collect();
+
+    let _ = format;
+    Ok(Table::new(name, columns, rows))
+}
+
+fn json_to_value(value: Option<&serde_json::Value>) -> Value {
+    match value {
+        None | Some(serde_json::Value::Null) => Value::Null,
+        Some(serde_json::Value::Bool(b)) => Value::Bool(*b),
+        Some(serde_json::Value::Number(n)) => {
+            n.as_i64().map(Value::Integer).unwrap_or_else(|| Value::Number(n.as_f64().unwrap_or_default()))
+        }
+        Some(serde_json::Value::String(s)) => Value::Text(s.clone()),
+        Some(other) => Value::Text(other.to_string()),
+    }
+}
+
+/// Parses a GitHub-flavored Markdown table: a header row, a `---`/`:--:`
+/// delimiter row, and one data row per remaining line.
+fn parse_markdown(name: &str, input: &str) -> Result<Table> {
+    let malformed = |detail: String| Error::Malformed { what: "markdown table", detail };
+
+    let mut lines = input.trim().lines().filter(|line| !line.trim().is_empty());
+
+    let header_line = lines
+        .next()
+        .ok_or_else(|| malformed("table is empty".to_string()))?;
+    let columns = split_markdown_row(header_line);
+
+    let delimiter_line = lines
+        .next()
+        .ok_or_else(|| malformed("missing header/body delimiter row".to_string()))?;
+    if !delimiter_line.trim().trim_matches('|').chars().all(|c| matches!(c, '-' | ':' | ' ' | '|')) {
+        return Err(malformed(format!("expected a `---` delimiter row, found {delimiter_line:?}")));
+    }
+
+    let rows = lines
+        .map(|line| {
+            let cells = split_markdown_row(line);
+            if cells.len() != columns.len() {
+                return Err(malformed(format!(
+                    "row has {} cells, expected {} to match the header",
+                    cells.len(),
+                    columns.len()
+                )));
+            }
+            Ok(cells.into_iter().map(Value::Text).collect())
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Table::new(name, columns, rows))
+}
+
+fn split_markdown_row(line: &str) -> Vec<String> {
+    line.trim().trim_matches('|').split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_every_format() {
+        assert_eq!(sniff_format("[{\"a\":1}]"), DataFormat::Json);
+        assert_eq!(sniff_format("{\"a\":1}"), DataFormat::Json);
+        assert_eq!(sniff_format("| a | b |\n|---|---|\n"), DataFormat::Markdown);
+        assert_eq!(sniff_format("a\tb\n1\t2\n"), DataFormat::Tsv);
+        assert_eq!(sniff_format("- a: 1\n"), DataFormat::Yaml);
+        assert_eq!(sniff_format("a,b\n1,2\n"), DataFormat::Csv);
+    }
+
+    #[test]
+    fn parses_csv() {
+        let table = parse_auto("t", "Name,Age\nAlice,30\nBob,40\n").unwrap();
+        assert_eq!(table.columns, vec!["Name", "Age"]);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.cell(0, "Name"), Some(&Value::Text("Alice".to_string())));
+    }
+
+    #[test]
+    fn parses_tsv() {
+        let table = parse_auto("t", "Name\tAge\nAlice\t30\n").unwrap();
+        assert_eq!(table.cell(0, "Age"), Some(&Value::Text("30".to_string())));
+    }
+
+    #[test]
+    fn parses_json_with_missing_fields_as_null() {
+        let table = parse_auto("t", r#"[{"a":1,"b":"x"},{"a":2}]"#).unwrap();
+        assert_eq!(table.columns, vec!["a", "b"]);
+        assert_eq!(table.cell(1, "b"), Some(&Value::Null));
+        assert_eq!(table.cell(0, "a"), Some(&Value::Integer(1)));
+    }
+
+    #[test]
+    fn parses_yaml_sequence() {
+        let table = parse_auto("t", "- Name: Alice\n  Role: Dev\n- Name: Bob\n  Role: QA\n").unwrap();
+        assert_eq!(table.columns, vec!["Name", "Role"]);
+        assert_eq!(table.cell(1, "Role"), Some(&Value::Text("QA".to_string())));
+    }
+
+    #[test]
+    fn parses_markdown_table() {
+        let table = parse_auto(
+            "t",
+            "| Name | Role |\n|------|------|\n| Alice | Dev |\n| Bob | QA |\n",
+        )
+        .unwrap();
+        assert_eq!(table.columns, vec!["Name", "Role"]);
+        assert_eq!(table.cell(1, "Name"), Some(&Value::Text("Bob".to_string())));
+    }
+
+    #[test]
+    fn explicit_format_overrides_sniffing() {
+        let table = parse_format("t", DataFormat::Csv, "Name,Age\nAlice,30\n").unwrap();
+        assert_eq!(table.columns, vec!["Name", "Age"]);
+    }
+}
@@ -0,0 +1,60 @@
+//! Runtime support for the `vivafolio_data!`/`vivafolio_report!` family of
+//! macros (see the `vivafolio-data-macros` crate). Everything the macros
+//! expand to is ordinary, documented, callable code living here: the macros
+//! are thin compile-time validators and code generators over this crate.
+
+pub mod table;
+pub mod formats;
+pub mod external;
+pub mod report;
+pub mod computed;
+pub mod rdf;
+pub mod compression;
+
+pub use table::{Table, Value};
+pub use error::{Error, Result};
+
+mod error {
+    use std::path::PathBuf;
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum Error {
+        #[error("unknown data format {0:?}")]
+        UnknownFormat(String),
+        #[error("failed to parse {format} data for table {table:?}: {source}")]
+        Parse {
+            table: String,
+            format: &'static str,
+            #[source]
+            source: Box<dyn std::error::Error + Send + Sync>,
+        },
+        #[error("malformed {what}: {detail}")]
+        Malformed { what: &'static str, detail: String },
+        #[error("external source file {0:?} not found")]
+        SourceNotFound(PathBuf),
+        #[error("failed to fetch {0:?}: {1}")]
+        Fetch(String, String),
+        #[error("database error: {0}")]
+        Sql(#[from] rusqlite::Error),
+        #[error("io error reading {0:?}: {1}")]
+        Io(PathBuf, std::io::Error),
+        #[error("invalid report template: {0}")]
+        TemplateParse(#[from] serde_yaml::Error),
+        #[error("report references undeclared parameter {0:?}")]
+        MissingParam(String),
+        #[error("report references column {0:?}, which is not in table {1:?}")]
+        MissingField(String, String),
+        #[error("computed column {0:?} participates in a dependency cycle")]
+        ComputedCycle(String),
+        #[error("lua evaluation failed for formula {formula:?}: {detail}")]
+        Lua { formula: String, detail: String },
+        #[error("unknown RDF export format {0:?}")]
+        UnknownExportFormat(String),
+        #[error("invalid RDF context: {0}")]
+        RdfContext(#[from] serde_json::Error),
+        #[error("zstd compression error: {0}")]
+        Zstd(std::io::Error),
+    }
+
+    pub type Result<T> = std::result::Result<T, Error>;
+}
@@ -0,0 +1,260 @@
+//! RDF export for [`Table`], driven by `vivafolio_rdf_export!`: a
+//! declarative JSON-LD [`Context`] maps columns to ontology properties (and,
+//! optionally, XSD datatypes), names the column that identifies each row's
+//! subject, and gives the IRI prefix subjects are minted under. Each row
+//! becomes one subject with one triple per mapped column; the result is
+//! serialized as either Turtle or JSON-LD.
+
+use crate::{Error, Result, Table, Value};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::Deserialize;
+use serde_json::{json, Value as Json};
+
+/// Characters an IRIREF (`<...>`) can't contain literally, per the Turtle
+/// and JSON-LD grammars, plus everything [`CONTROLS`] already excludes:
+/// whitespace, `<>"{}|^\`` and the backtick.
+const IRI_COMPONENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'<')
+    .add(b'>')
+    .add(b'"')
+    .add(b'{')
+    .add(b'}')
+    .add(b'|')
+    .add(b'^')
+    .add(b'\\')
+    .add(b'`');
+
+/// Mints a subject IRI for a row's id value: the configured prefix, followed
+/// by the value with any character an IRIREF can't contain literally
+/// percent-encoded.
+fn subject_iri(prefix: &str, id: &str) -> String {
+    format!("{prefix}{}", utf8_percent_encode(id, IRI_COMPONENT))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Turtle,
+    JsonLd,
+}
+
+impl ExportFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::Turtle => "turtle",
+            ExportFormat::JsonLd => "json-ld",
+        }
+    }
+
+    pub fn parse_name(name: &str) -> Result<Self> {
+        match name {
+            "turtle" | "ttl" => Ok(ExportFormat::Turtle),
+            "json-ld" | "jsonld" => Ok(ExportFormat::JsonLd),
+            other => Err(Error::UnknownExportFormat(other.to_string())),
+        }
+    }
+}
+
+/// A parsed `rdf-context.jsonld`: which column identifies the subject, what
+/// IRI prefix to mint subjects under, and the raw JSON-LD `@context` object
+/// mapping column names to properties (kept as [`Json`] so it can be
+/// embedded verbatim in JSON-LD output).
+#[derive(Debug, Deserialize)]
+pub struct Context {
+    #[serde(rename = "@context")]
+    properties: Json,
+    pub subject_column: String,
+    pub subject_prefix: String,
+}
+
+struct PropertyMapping {
+    iri: String,
+    datatype: Option<String>,
+}
+
+impl Context {
+    fn mapped_columns(&self) -> impl Iterator<Item = &str> {
+        self.properties.as_object().into_iter().flat_map(|map| map.keys().map(String::as_str))
+    }
+
+    fn mapping(&self, column: &str) -> Option<PropertyMapping> {
+        let value = self.properties.get(column)?;
+        if let Some(iri) = value.as_str() {
+            return Some(PropertyMapping { iri: iri.to_string(), datatype: None });
+        }
+        let object = value.as_object()?;
+        let iri = object.get("@id")?.as_str()?.to_string();
+        let datatype = object.get("@type").and_then(Json::as_str).map(str::to_string);
+        Some(PropertyMapping { iri, datatype })
+    }
+}
+
+pub fn parse_context(json: &str) -> Result<Context> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// A single RDF triple produced from one table cell.
+struct Triple {
+    subject: String,
+    predicate: String,
+    object: Object,
+}
+
+enum Object {
+    Literal { value: String, datatype: Option<String> },
+}
+
+/// Parses `context_json` and converts `table` to `format`.
+pub fn export(table: &Table, context_json: &str, format: ExportFormat) -> Result<String> {
+    let context = parse_context(context_json)?;
+    match format {
+        ExportFormat::Turtle => Ok(serialize_turtle(&to_triples(table, &context)?)),
+        ExportFormat::JsonLd => serialize_jsonld(table, &context),
+    }
+}
+
+fn to_triples(table: &Table, context: &Context) -> Result<Vec<Triple>> {
+    let subject_index = table
+        .column_index(&context.subject_column)
+        .ok_or_else(|| Error::MissingField(context.subject_column.clone(), table.name.clone()))?;
+
+    let mut triples = Vec::new();
+    for row in &table.rows {
+        let subject = subject_iri(&context.subject_prefix, &row[subject_index].display());
+
+        for column in context.mapped_columns() {
+            let index = table
+                .column_index(column)
+                .ok_or_else(|| Error::MissingField(column.to_string(), table.name.clone()))?;
+            if row[index] == Value::Null {
+                continue;
+            }
+
+            let mapping = context.mapping(column).expect("column came from the context's own keys");
+            triples.push(Triple {
+                subject: subject.clone(),
+                predicate: mapping.iri,
+                object: Object::Literal { value: row[index].display(), datatype: mapping.datatype },
+            });
+        }
+    }
+
+    Ok(triples)
+}
+
+/// Renders `triples` as `<subject> <predicate> "value"[^^<datatype>] .`
+/// lines, one per triple.
+fn serialize_turtle(triples: &[Triple]) -> String {
+    let mut out = String::new();
+    for triple in triples {
+        let Object::Literal { value, datatype } = &triple.object;
+        let literal = match datatype {
+            Some(datatype) => format!("{value:?}^^<{datatype}>"),
+            None => format!("{value:?}"),
+        };
+        out.push_str(&format!("<{}> <{}> {} .\n", triple.subject, triple.predicate, literal));
+    }
+    out
+}
+
+/// Renders `table` as a JSON-LD document: the context reused verbatim from
+/// `context`, and one `@graph` node per row with one field per mapped
+/// column (keyed by the original column name, same as `context` expects).
+fn serialize_jsonld(table: &Table, context: &Context) -> Result<String> {
+    let subject_index = table
+        .column_index(&context.subject_column)
+        .ok_or_else(|| Error::MissingField(context.subject_column.clone(), table.name.clone()))?;
+
+    let mut nodes = Vec::with_capacity(table.rows.len());
+    for row in &table.rows {
+        let mut node = serde_json::Map::new();
+        node.insert("@id".to_string(), json!(subject_iri(&context.subject_prefix, &row[subject_index].display())));
+
+        for column in context.mapped_columns() {
+            let index = table
+                .column_index(column)
+                .ok_or_else(|| Error::MissingField(column.to_string(), table.name.clone()))?;
+            if row[index] != Value::Null {
+                node.insert(column.to_string(), json!(row[index].display()));
+            }
+        }
+
+        nodes.push(Json::Object(node));
+    }
+
+    let document = json!({ "@context": context.properties, "@graph": nodes });
+    Ok(serde_json::to_string_pretty(&document)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTEXT: &str = r#"{
+        "@context": {
+            "Name": "http://xmlns.com/foaf/0.1/name",
+            "Role": { "@id": "http://schema.org/jobTitle" },
+            "Start Date": { "@id": "http://schema.org/startDate", "@type": "http://www.w3.org/2001/XMLSchema#date" }
+        },
+        "subject_column": "Name",
+        "subject_prefix": "https://vivafolio.example/team/"
+    }"#;
+
+    fn team_table() -> Table {
+        Table::new(
+            "team_members",
+            vec!["Name".into(), "Role".into(), "Start Date".into()],
+            vec![vec![Value::Text("Alice".into()), Value::Text("Senior Developer".into()), Value::Text("2023-01-15".into())]],
+        )
+    }
+
+    #[test]
+    fn exports_turtle() {
+        let turtle = export(&team_table(), CONTEXT, ExportFormat::Turtle).unwrap();
+        assert!(turtle.contains("<https://vivafolio.example/team/Alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" ."));
+        assert!(turtle.contains(
+            "<https://vivafolio.example/team/Alice> <http://schema.org/startDate> \"2023-01-15\"^^<http://www.w3.org/2001/XMLSchema#date> ."
+        ));
+    }
+
+    #[test]
+    fn exports_json_ld() {
+        let doc = export(&team_table(), CONTEXT, ExportFormat::JsonLd).unwrap();
+        let parsed: Json = serde_json::from_str(&doc).unwrap();
+        assert_eq!(parsed["@graph"][0]["@id"], "https://vivafolio.example/team/Alice");
+        assert_eq!(parsed["@graph"][0]["Role"], "Senior Developer");
+    }
+
+    #[test]
+    fn subject_ids_with_spaces_are_percent_encoded() {
+        let table = Table::new(
+            "team_members",
+            vec!["Name".into(), "Role".into(), "Start Date".into()],
+            vec![vec![
+                Value::Text("Alice Smith".into()),
+                Value::Text("Senior Developer".into()),
+                Value::Text("2023-01-15".into()),
+            ]],
+        );
+
+        let turtle = export(&table, CONTEXT, ExportFormat::Turtle).unwrap();
+        assert!(turtle.contains("<https://vivafolio.example/team/Alice%20Smith> <http://xmlns.com/foaf/0.1/name> \"Alice Smith\" ."));
+        assert!(!turtle.contains("team/Alice Smith>"));
+
+        let doc = export(&table, CONTEXT, ExportFormat::JsonLd).unwrap();
+        let parsed: Json = serde_json::from_str(&doc).unwrap();
+        assert_eq!(parsed["@graph"][0]["@id"], "https://vivafolio.example/team/Alice%20Smith");
+    }
+
+    #[test]
+    fn unknown_subject_column_is_an_error() {
+        let context = r#"{"@context": {}, "subject_column": "Nonexistent", "subject_prefix": "urn:"}"#;
+        let error = export(&team_table(), context, ExportFormat::Turtle).unwrap_err();
+        assert!(matches!(error, Error::MissingField(column, _) if column == "Nonexistent"));
+    }
+
+    #[test]
+    fn unknown_format_name_is_an_error() {
+        assert!(matches!(ExportFormat::parse_name("xml"), Err(Error::UnknownExportFormat(name)) if name == "xml"));
+    }
+}
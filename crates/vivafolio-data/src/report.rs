@@ -0,0 +1,411 @@
+//! The renderer backing `vivafolio_report!`: walks a YAML report template's
+//! sections in order over a bound [`Table`], substituting `$F(column)` from
+//! each row and `$P{name}` from caller-supplied parameters.
+
+use crate::{Error, Result, Table};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+#[derive(Debug, Deserialize)]
+pub struct Template {
+    pub title: String,
+    /// Rows per page. Omitted (or `0`) means the whole table is one page;
+    /// otherwise `column_header` is repeated at the top of every page and
+    /// pages are separated per [`RenderFormat`].
+    #[serde(default)]
+    pub page_size: usize,
+    #[serde(default)]
+    pub page_header: Vec<Cell>,
+    #[serde(default)]
+    pub column_header: Vec<ColumnHeader>,
+    #[serde(default)]
+    pub row: Vec<Cell>,
+    #[serde(default)]
+    pub column_footer: Vec<Cell>,
+    #[serde(default)]
+    pub page_footer: Vec<Cell>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ColumnHeader {
+    pub name: String,
+    /// Minimum column width, in characters. `0` (the default) means "as
+    /// wide as its content" — no padding.
+    #[serde(default)]
+    pub width: usize,
+}
+
+/// Output produced by [`render_report`]/[`render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    /// Columns padded to their declared `width` and joined with `" | "`.
+    Text,
+    /// An HTML `<table>` per page, with `column_header` widths applied as
+    /// inline `style="width: ...ch"`.
+    Html,
+}
+
+/// A single cell: either a literal (substituting `$P{...}` parameters) or a
+/// field reference (substituting `$F(...)` from the current row).
+#[derive(Debug, Deserialize)]
+pub struct Cell {
+    pub text: Option<String>,
+    pub field: Option<String>,
+}
+
+static PARAM_PATTERN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\$P\{([^}]+)\}").expect("valid regex"));
+static FIELD_PATTERN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\$F\(([^)]+)\)").expect("valid regex"));
+
+/// Builds the `HashMap<String, String>` that [`render_report`] expects,
+/// converting both sides with `.to_string()`.
+///
+/// ```
+/// let params = vivafolio_data::params! { "company_name" => "Vivafolio Inc." };
+/// assert_eq!(params["company_name"], "Vivafolio Inc.");
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut map = ::std::collections::HashMap::new();
+        $(map.insert($key.to_string(), $value.to_string());)*
+        map
+    }};
+}
+
+pub fn parse_template(yaml: &str) -> Result<Template> {
+    Ok(serde_yaml::from_str(yaml)?)
+}
+
+/// Renders `yaml` (a report template, see [`Template`]) against `table`,
+/// substituting `params` into `$P{...}` placeholders. Produces a paginated
+/// report in `format`: page header once, then one page per `page_size` rows
+/// (the whole table if unset), each with its own column header, rows, and
+/// column footer, then the page footer once.
+pub fn render_report(
+    yaml: &str,
+    table: &Table,
+    params: &HashMap<String, String>,
+    format: RenderFormat,
+) -> Result<String> {
+    let template = parse_template(yaml)?;
+    render(&template, table, params, format)
+}
+
+pub fn render(
+    template: &Template,
+    table: &Table,
+    params: &HashMap<String, String>,
+    format: RenderFormat,
+) -> Result<String> {
+    let title = substitute_params(&template.title, params)?;
+    let page_header = render_literal_cells(&template.page_header, params)?;
+    let page_footer = render_literal_cells(&template.page_footer, params)?;
+    let column_footer = render_literal_cells(&template.column_footer, params)?;
+    let widths: Vec<usize> = template.column_header.iter().map(|column| column.width).collect();
+    let headers: Vec<String> = template.column_header.iter().map(|column| column.name.clone()).collect();
+
+    let page_size = if template.page_size == 0 { table.rows.len().max(1) } else { template.page_size };
+    let mut pages = Vec::new();
+    for page_rows in (0..table.rows.len()).collect::<Vec<_>>().chunks(page_size) {
+        let mut rows = Vec::with_capacity(page_rows.len());
+        for &row_index in page_rows {
+            rows.push(
+                template
+                    .row
+                    .iter()
+                    .map(|cell| render_row_cell(cell, table, row_index, params))
+                    .collect::<Result<Vec<_>>>()?,
+            );
+        }
+        pages.push(rows);
+    }
+    if pages.is_empty() {
+        pages.push(Vec::new());
+    }
+
+    match format {
+        RenderFormat::Text => Ok(render_text(&title, &page_header, &headers, &widths, &pages, &column_footer, &page_footer)),
+        RenderFormat::Html => Ok(render_html(&title, &page_header, &headers, &widths, &pages, &column_footer, &page_footer)),
+    }
+}
+
+fn render_text(
+    title: &str,
+    page_header: &[String],
+    headers: &[String],
+    widths: &[usize],
+    pages: &[Vec<Vec<String>>],
+    column_footer: &[String],
+    page_footer: &[String],
+) -> String {
+    let mut out = String::new();
+    out.push_str(title);
+    out.push('\n');
+    for line in page_header {
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    for (page_index, rows) in pages.iter().enumerate() {
+        if page_index > 0 {
+            out.push('\x0c'); // form feed: page break
+        }
+        if !headers.is_empty() {
+            out.push_str(&pad_row(headers, widths).join(" | "));
+            out.push('\n');
+        }
+        for row in rows {
+            out.push_str(&pad_row(row, widths).join(" | "));
+            out.push('\n');
+        }
+        for line in column_footer {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+
+    for line in page_footer {
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Pads each cell to its column's declared width (left-aligned); a column
+/// with no declared width (`0`) or past the end of `widths` is left as-is.
+fn pad_row(cells: &[String], widths: &[usize]) -> Vec<String> {
+    cells
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| match widths.get(index) {
+            Some(&width) if width > cell.chars().count() => format!("{cell:<width$}"),
+            _ => cell.clone(),
+        })
+        .collect()
+}
+
+fn render_html(
+    title: &str,
+    page_header: &[String],
+    headers: &[String],
+    widths: &[usize],
+    pages: &[Vec<Vec<String>>],
+    column_footer: &[String],
+    page_footer: &[String],
+) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("<h1>{}</h1>\n", html_escape(title)));
+    for line in page_header {
+        out.push_str(&format!("<p>{}</p>\n", html_escape(line)));
+    }
+
+    for rows in pages {
+        out.push_str("<table>\n");
+        if !headers.is_empty() {
+            out.push_str("<tr>");
+            for (index, name) in headers.iter().enumerate() {
+                match widths.get(index) {
+                    Some(&width) if width > 0 => {
+                        out.push_str(&format!("<th style=\"width: {width}ch\">{}</th>", html_escape(name)))
+                    }
+                    _ => out.push_str(&format!("<th>{}</th>", html_escape(name))),
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        for row in rows {
+            out.push_str("<tr>");
+            for cell in row {
+                out.push_str(&format!("<td>{}</td>", html_escape(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+        for line in column_footer {
+            out.push_str(&format!("<p>{}</p>\n", html_escape(line)));
+        }
+    }
+
+    for line in page_footer {
+        out.push_str(&format!("<p>{}</p>\n", html_escape(line)));
+    }
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn render_literal_cells(cells: &[Cell], params: &HashMap<String, String>) -> Result<Vec<String>> {
+    cells.iter().map(|cell| render_literal_cell(cell, params)).collect()
+}
+
+fn render_literal_cell(cell: &Cell, params: &HashMap<String, String>) -> Result<String> {
+    match &cell.text {
+        Some(text) => substitute_params(text, params),
+        None => Ok(String::new()),
+    }
+}
+
+fn render_row_cell(
+    cell: &Cell,
+    table: &Table,
+    row_index: usize,
+    params: &HashMap<String, String>,
+) -> Result<String> {
+    if let Some(field_expr) = &cell.field {
+        substitute_fields(field_expr, table, row_index)
+    } else if let Some(text) = &cell.text {
+        substitute_params(text, params)
+    } else {
+        Ok(String::new())
+    }
+}
+
+fn substitute_params(text: &str, params: &HashMap<String, String>) -> Result<String> {
+    let mut error = None;
+    let rendered = PARAM_PATTERN.replace_all(text, |captures: &regex::Captures| {
+        let name = &captures[1];
+        match params.get(name) {
+            Some(value) => value.clone(),
+            None => {
+                error.get_or_insert_with(|| Error::MissingParam(name.to_string()));
+                String::new()
+            }
+        }
+    });
+    match error {
+        Some(error) => Err(error),
+        None => Ok(rendered.into_owned()),
+    }
+}
+
+fn substitute_fields(text: &str, table: &Table, row_index: usize) -> Result<String> {
+    let mut error = None;
+    let rendered = FIELD_PATTERN.replace_all(text, |captures: &regex::Captures| {
+        let column = &captures[1];
+        match table.cell(row_index, column) {
+            Some(value) => value.display(),
+            None => {
+                error.get_or_insert_with(|| Error::MissingField(column.to_string(), table.name.clone()));
+                String::new()
+            }
+        }
+    });
+    match error {
+        Some(error) => Err(error),
+        None => Ok(rendered.into_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Value;
+
+    fn sample_table() -> Table {
+        Table::new(
+            "project_tasks",
+            vec!["Task Name".into(), "Priority".into()],
+            vec![
+                vec![Value::Text("Implement authentication".into()), Value::Text("High".into())],
+                vec![Value::Text("Design database schema".into()), Value::Text("Medium".into())],
+            ],
+        )
+    }
+
+    const TEMPLATE: &str = r#"
+title: "$P{company_name} Report"
+page_header:
+  - { text: "Tasks" }
+column_header:
+  - { name: Task Name }
+  - { name: Priority }
+row:
+  - { field: "$F(Task Name)" }
+  - { field: "$F(Priority)" }
+column_footer:
+  - { text: "Average priority: $P{average_priority}" }
+page_footer:
+  - { text: "Generated for $P{company_name}" }
+"#;
+
+    #[test]
+    fn renders_full_report() {
+        let mut params = HashMap::new();
+        params.insert("company_name".to_string(), "Vivafolio Inc.".to_string());
+        params.insert("average_priority".to_string(), "Medium".to_string());
+
+        let report = render_report(TEMPLATE, &sample_table(), &params, RenderFormat::Text).unwrap();
+        assert!(report.contains("Vivafolio Inc. Report"));
+        assert!(report.contains("Implement authentication | High"));
+        assert!(report.contains("Design database schema | Medium"));
+        assert!(report.contains("Average priority: Medium"));
+        assert!(report.contains("Generated for Vivafolio Inc."));
+    }
+
+    #[test]
+    fn missing_param_is_an_error() {
+        let error = render_report(TEMPLATE, &sample_table(), &HashMap::new(), RenderFormat::Text).unwrap_err();
+        assert!(matches!(error, Error::MissingParam(name) if name == "company_name"));
+    }
+
+    #[test]
+    fn missing_field_is_an_error() {
+        let template = r#"
+title: t
+row:
+  - { field: "$F(Nonexistent)" }
+"#;
+        let error =
+            render_report(template, &sample_table(), &HashMap::new(), RenderFormat::Text).unwrap_err();
+        assert!(matches!(error, Error::MissingField(column, _) if column == "Nonexistent"));
+    }
+
+    #[test]
+    fn column_header_widths_are_padded_in_text_output() {
+        let template = r#"
+title: t
+column_header:
+  - { name: Task, width: 10 }
+  - { name: Priority, width: 6 }
+row:
+  - { field: "$F(Task Name)" }
+  - { field: "$F(Priority)" }
+"#;
+        let report = render_report(template, &sample_table(), &HashMap::new(), RenderFormat::Text).unwrap();
+        assert!(report.contains("Task       | Priority"));
+        assert!(report.contains("High  "));
+    }
+
+    #[test]
+    fn html_output_is_a_table_per_page() {
+        let mut params = HashMap::new();
+        params.insert("company_name".to_string(), "Vivafolio Inc.".to_string());
+        params.insert("average_priority".to_string(), "Medium".to_string());
+
+        let html = render_report(TEMPLATE, &sample_table(), &params, RenderFormat::Html).unwrap();
+        assert!(html.contains("<h1>Vivafolio Inc. Report</h1>"));
+        assert!(html.contains("<th>Task Name</th>"));
+        assert!(html.contains("<td>Implement authentication</td><td>High</td>"));
+    }
+
+    #[test]
+    fn page_size_splits_rows_into_multiple_pages() {
+        let template = r#"
+title: t
+page_size: 1
+column_header:
+  - { name: Task Name }
+row:
+  - { field: "$F(Task Name)" }
+"#;
+        let report = render_report(template, &sample_table(), &HashMap::new(), RenderFormat::Text).unwrap();
+        assert_eq!(report.matches("Task Name\n").count(), 2);
+        assert!(report.contains('\x0c'));
+    }
+}
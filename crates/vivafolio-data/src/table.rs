@@ -0,0 +1,88 @@
+//! The column/row model every `vivafolio_data!` source format (CSV, TSV,
+//! JSON, YAML, Markdown, external, computed, ...) normalizes into. Rendering,
+//! export, and formula evaluation are all written against this type rather
+//! than against any individual source format.
+
+use std::fmt;
+
+/// A single table cell. Textual formats (CSV/TSV/Markdown) always produce
+/// [`Value::Text`]; formats with native typing (JSON/YAML) preserve it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Integer(i64),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    /// Renders the value the way it should appear in text/HTML output and
+    /// RDF literals: no quoting, no `Some(..)` debug noise.
+    pub fn display(&self) -> String {
+        match self {
+            Value::Text(s) => s.clone(),
+            Value::Integer(i) => i.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Null => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+/// A named table: an ordered list of columns plus rows of cells, one cell
+/// per column per row. This is the shape every `vivafolio_data!` format
+/// parser, external-source loader, and computed-column pass both consumes
+/// and produces.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    pub fn new(name: impl Into<String>, columns: Vec<String>, rows: Vec<Vec<Value>>) -> Self {
+        Table { name: name.into(), columns, rows }
+    }
+
+    pub fn column_index(&self, column: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == column)
+    }
+
+    pub fn cell(&self, row: usize, column: &str) -> Option<&Value> {
+        let index = self.column_index(column)?;
+        self.rows.get(row)?.get(index)
+    }
+
+    /// Appends a new column, filling existing rows with `values` (one entry
+    /// per row, in order). Used by [`crate::computed`] to graft a derived
+    /// column onto an existing table without mutating the originals in
+    /// place.
+    pub fn with_column(mut self, name: impl Into<String>, values: Vec<Value>) -> Self {
+        assert_eq!(values.len(), self.rows.len(), "one value per row required");
+        self.columns.push(name.into());
+        for (row, value) in self.rows.iter_mut().zip(values) {
+            row.push(value);
+        }
+        self
+    }
+}
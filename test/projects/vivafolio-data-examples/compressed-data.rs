@@ -0,0 +1,33 @@
+// Example demonstrating zstd-compressed storage for large vivafolio_data!()
+// tables. The payload is a base64-encoded zstd blob of the plaintext CSV
+// below (200 rows of city/population data); it is transparently decoded and
+// decompressed at load into the same column/row model used by inline
+// tables.
+//
+// An existing plain-literal table can be converted to this form in place
+// with the `vivafolio-data-compress` tool:
+//
+//     cargo run -p vivafolio-data-tools --bin vivafolio-data-compress -- \
+//         test/projects/vivafolio-data-examples/compressed-data.rs big_dataset
+
+use vivafolio_data_macros::vivafolio_data;
+
+vivafolio_data!("big_dataset", compression = "zstd", r#"
+KLUv/QBo1TAAamBgDB9QT7MczLDNdxunYPl3Hf7zijxvUpJSypQ6GjMz2FUN/ACoAKYAXZWmcv6h4IjUUsLVpqxKkaCtJes+6PljTlIvyMLixRJEJ9XToQnKTSQrh04iES5gSIiggYvTAgcPFB5ocKBAwGEkepSCCAwJEiQYcKAJSpxoxF59Lq7GAwQ0hAMYOPD0N/5fPjRw8GCChYYADhIT0/pLcCxu3Ehefqx04V3uc9SrXFJ6HRQaNFiogEFBoYGAA7kc34FBA4UFBx5gWGiQYIHDTLyoBEGhAYaEhgEO1Za1gwKDhQkaFCpYUOBQBAAWYODgGFEnWGCQgMCBCAkMEhgSBDjUbH8PGBYaCHAYwgUNDxgocCDAgcHBwoOEBhRI4CCBwwUPFgQ4MCggu8Tdxzkiv19DJoZ0ykVDDiISZ+YkO8mspq0tJym6ZnbMcl4alsdnLxFHvdXqOqikJGLKa82ktrkjcQTr0nlV1PmjzDmL2OHqCpJ6FbNJUSVuQxM1oxB1VtZhV6gy69bOYxDn6WdDnnuv/zXkF2liKDoLzohP4szplSMxtW6qWNmMQstl9pU/7KzmvpTCDo6QXEZkMY4ql1JjJKXTSa2WkOHID3F9XM4TpFq6zxcRR7Riri5sjNBGKiEbh+z2TE9JuVpBpKJ6pH5T1iMNE1G0pOrI2M9Ev67xWrQuf3TkmG6NUPEjdmEaXvpTWCG1XFlFkkJWj7XSgqVpfSPVtoi8WokpKl5kZjMM2rwz6irqM6GKfQt2IsbyyEvaOLb31QqamlFGCGOeFFG4GBvzyjqLpxejfiY2jfjljs/jbqpeRSNzKGL+dXFyOunLGX8aqj1FUPjfNeILdVN3OE73PKpr5SdPfxYN+Rgieq+aK25W4rRe5pGmZq4uplVIajMVWsSfUtQRrir9mTvbMQpTXvHy0Os6SVWsiVo0/VRRySbuhNKSMlh8TV8FRRFV5JdxaOpnZopEqDiqjiYa2XQhmfMuUUke0uyTjKTlBK3ouuvjMRniulk0bfGzcypkGBXuRAnPkdejFiSzmClb6uIaV1JEWkpkFjKSiYGJqBHwOGIkK0UlSVoDcYzQYZJc5wEhCIUhSpNlNwdxKE1M+A/7YpyHUpvvYbKejQYk4qw9iEgzLYuVqUIS0ea+5JLaA0XbsC4DRayhMEro1jqWQCluy9rLkafF77kVn7+HW0j0ujWJpsj2nXfJVKhm5SkuysAYCBBqZp1PXiDyXOn3F1Xon+uKLOx6ErFIlkuJfyoXIDvaqdGQZARa9c7nlbsDWhI7k++c+j9N7vAXIEMT7HZHa2NlvoGv0me6QFso9j/STL4WmYnzer4iYC/vLIVyUtZkM51lWvMRReniSrhTgYCbfXwa55xGPmHs5KRtg9jfpprFsABFomf72c/R/mVn156KZv8xZN43P2mk43BzRDgfVD1/CDh3rhwz0BIRuJQY5S2yecWRjbg5Q7XIE6l4T3PFSQp+RFLTXjNMyab4F2ttk9EJK3XPVqxdhnw+l7gYr61eZLBMDfLptAYc4jMw2/gFsyASrJCVWE8erSRKlD9DLlPS0FkgraAleZoWjoVqnhbwFvs/SVaz3qfk8toi4yBA9+ISDjR5tNJxsyxOW/aJVViQlP5ACWcpcYwc/BPYmsJt4L4P+owN25Sq/xqnZN7z5obKgbKzG2Td1Fg0bsB6s+skLbsZ3qxM02vmtj+853ygPGjum8LrrE+Q/VgNarfQ1N5S1AJecyxHOLSizhMLsLYcTRoDJ1oEkvzJ2Wo1wvp4oqXaT2yUuRVxkHDhHQyhg1kslQ20wZjpCDqfVGEPr/6L0AgRLgwyxFdXmgEkiQgJ1FBS3McqbNYLtDmIo0+v4KvRpKepbgfZwK03Dt2zIK1qlM6JtTNWT4zzWubZDaNuBLaKvnEirtJ2ajPz3rTmoJ7PVPUBUlmX9o4NwCd7ZqFvIAke6NNG6gOb6j0cVMgA9aVBULh2HijmkcMpP4iKiOZgAPWP6wGHph2XlIQnnXGDaH/Iv4wpBtqa3Pi7UIX1mKYtrbOSOBHma02rbctMFOgeRMQJEgEWpGi4m6Tf8VT4V1UFDFU=
+"#);
+
+// Small tables stay human-readable as plain literals.
+vivafolio_data!("small_dataset", r#"
+Id,Value
+1,alpha
+2,beta
+"#);
+
+fn main() {
+    let big = big_dataset().expect("big_dataset decompresses and parses");
+    println!("big_dataset: {} columns, {} rows", big.columns.len(), big.rows.len());
+    println!("first row: {:?}", big.rows[0]);
+
+    let small = small_dataset().expect("small_dataset parses");
+    println!("small_dataset: {} columns, {} rows", small.columns.len(), small.rows.len());
+}
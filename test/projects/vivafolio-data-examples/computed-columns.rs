@@ -0,0 +1,35 @@
+// Example demonstrating computed columns in vivafolio_data!(), evaluated by
+// an embedded, pure-Rust Lua VM. Each formula sees the other columns of its
+// own row (base or already-computed) as `$F(...)` references, and computed
+// columns may reference each other as long as there's no cycle between them.
+
+use vivafolio_data_macros::{vivafolio_data, vivafolio_data_column};
+
+vivafolio_data!("project_tasks", r#"
+Task Name,Assignee,Status,Priority,Due Date
+Implement authentication,Alice,In Progress,High,2025-09-20
+Design database schema,Bob,Completed,Medium,2025-09-15
+Write API documentation,Charlie,Not Started,Low,2025-09-25
+"#);
+
+// "Days Left" is a Lua expression over `$F(Due Date)`; "Overdue" references
+// "Days Left" right back, so the two are topologically ordered (and
+// re-checked for cycles) at compile time before either is ever evaluated.
+vivafolio_data_column!("project_tasks", {
+    "Days Left" => "days_between(today(), $F(Due Date))",
+    "Overdue" => "$F(Days Left) < 0",
+});
+
+fn main() {
+    let table = project_tasks().expect("project_tasks table parses");
+    let table = project_tasks_with_computed_columns(table).expect("computed columns evaluate");
+
+    println!("columns: {:?}", table.columns);
+    for row in 0..table.rows.len() {
+        let name = table.cell(row, "Task Name").unwrap().display();
+        let due = table.cell(row, "Due Date").unwrap().display();
+        let days_left = table.cell(row, "Days Left").unwrap().display();
+        let overdue = table.cell(row, "Overdue").unwrap().display();
+        println!("{name} (due {due}): {days_left} days left, overdue = {overdue}");
+    }
+}
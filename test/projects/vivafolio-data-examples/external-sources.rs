@@ -0,0 +1,46 @@
+// Example demonstrating vivafolio_data!() bound to an external source instead
+// of an inline literal. The table is loaded at runtime and kept in sync with
+// the source, re-reading on file change or an explicit refresh command.
+//
+// `from_file`/`from_url` paths are resolved relative to this crate's
+// Cargo.toml (`CARGO_MANIFEST_DIR`), so the fixture lives under
+// `fixtures/tasks.csv` there rather than next to this file.
+
+use vivafolio_data::external::{Connection, ExternalTable};
+use vivafolio_data_macros::vivafolio_data;
+
+vivafolio_data!("tasks_from_file", from_file = "fixtures/tasks.csv");
+
+// A `file://` URL is resolved locally (as here, so this example has no
+// network dependency); any other scheme is fetched over HTTP.
+vivafolio_data!("holidays_from_url", from_url = "file://fixtures/tasks.csv");
+
+// Backed by a SQL query against a connection supplied at call time.
+vivafolio_data!("tasks_from_sql", from_sql = "SELECT name, assignee, status FROM tasks");
+
+// Regular code continues below...
+fn main() {
+    let file_table = tasks_from_file().expect("fixtures/tasks.csv parses");
+    println!("tasks_from_file: {} row(s)", file_table.rows.len());
+
+    let url_table = holidays_from_url().expect("file:// url resolves");
+    println!("holidays_from_url: {} row(s)", url_table.rows.len());
+
+    let connection = Connection::open_in_memory().expect("open sqlite connection");
+    connection
+        .execute_batch(
+            "CREATE TABLE tasks (name TEXT, assignee TEXT, status TEXT);
+             INSERT INTO tasks VALUES ('Implement authentication', 'Alice', 'In Progress');
+             INSERT INTO tasks VALUES ('Design database schema', 'Bob', 'Completed');",
+        )
+        .expect("seed sqlite database");
+    let sql_table = tasks_from_sql(&connection).expect("query executes");
+    println!("tasks_from_sql: {} row(s)", sql_table.rows.len());
+
+    // `refresh()` only re-reads the file if its mtime has advanced, so
+    // polling it repeatedly (e.g. on an editor idle timer) is cheap.
+    let fixture_path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/tasks.csv");
+    let mut watched = ExternalTable::open("tasks_from_file", fixture_path).expect("open tasks.csv");
+    let reloaded = watched.refresh().expect("refresh tasks.csv");
+    println!("explicit refresh reloaded data: {reloaded}");
+}
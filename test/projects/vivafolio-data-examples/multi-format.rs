@@ -0,0 +1,47 @@
+// Example demonstrating the additional inline formats accepted by vivafolio_data!()
+// Format is auto-detected from the leading characters of the literal, or can be
+// pinned explicitly with `format = "..."`.
+
+use vivafolio_data_macros::vivafolio_data;
+
+vivafolio_data!("tasks_json", format = "json", r#"
+[
+  {"Task Name": "Implement authentication", "Assignee": "Alice", "Status": "In Progress", "Priority": "High"},
+  {"Task Name": "Design database schema", "Assignee": "Bob", "Status": "Completed", "Priority": "Medium"}
+]
+"#);
+
+// No `format` argument: the leading `|` is sniffed as a Markdown table.
+vivafolio_data!("tasks_markdown", r#"
+| Task Name                | Assignee | Status      | Priority |
+|---------------------------|----------|-------------|----------|
+| Write API documentation   | Charlie  | Not Started | Low      |
+| Setup CI/CD pipeline      | Alice    | In Progress | High     |
+"#);
+
+// No `format` argument: tab-delimited rows are sniffed as TSV.
+vivafolio_data!("tasks_tsv", "Task Name\tAssignee\tStatus\tPriority\nUser acceptance testing\tDiana\tNot Started\tMedium\n");
+
+// YAML sequence form, explicit for clarity.
+vivafolio_data!("tasks_yaml", format = "yaml", r#"
+- Task Name: Review security audit
+  Assignee: Bob
+  Status: In Progress
+  Priority: High
+"#);
+
+// Regular code continues below...
+fn main() {
+    for (label, table) in [
+        ("json", tasks_json().expect("tasks_json parses")),
+        ("markdown", tasks_markdown().expect("tasks_markdown parses")),
+        ("tsv", tasks_tsv().expect("tasks_tsv parses")),
+        ("yaml", tasks_yaml().expect("tasks_yaml parses")),
+    ] {
+        println!("{label}: columns {:?}, {} row(s)", table.columns, table.rows.len());
+        for row in &table.rows {
+            let rendered: Vec<String> = row.iter().map(|cell| cell.display()).collect();
+            println!("  {}", rendered.join(" | "));
+        }
+    }
+}
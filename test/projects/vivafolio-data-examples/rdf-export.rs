@@ -0,0 +1,27 @@
+// Example demonstrating RDF / JSON-LD export of a vivafolio_data!() table
+// via a reusable, declarative column context.
+//
+// Like `from_file`, the context path is resolved relative to this crate's
+// Cargo.toml, so it lives under `fixtures/rdf-context.jsonld` there rather
+// than next to this file.
+
+use vivafolio_data_macros::{vivafolio_data, vivafolio_rdf_export};
+
+vivafolio_data!("team_members", r#"
+Name,Role,Department,Start Date
+Alice,Senior Developer,Engineering,2023-01-15
+Bob,Database Administrator,Engineering,2022-08-20
+Charlie,Technical Writer,Documentation,2024-03-10
+Diana,QA Engineer,Testing,2023-11-05
+"#);
+
+// The context maps columns to ontology properties and datatypes, and names
+// the subject-identifier column. Contexts are stored in their own file so a
+// team can share and reuse them across tables.
+vivafolio_rdf_export!("team_members", context = "fixtures/rdf-context.jsonld", format = "turtle");
+
+fn main() {
+    let table = team_members().expect("team_members table parses");
+    let turtle = export_team_members_rdf(&table).expect("RDF export succeeds");
+    print!("{turtle}");
+}
@@ -0,0 +1,33 @@
+// Example demonstrating vivafolio_report!() rendering a YAML report template
+// over a table defined with vivafolio_data!().
+//
+// Like `from_file`, the template path is resolved relative to this crate's
+// Cargo.toml, so the template lives under `fixtures/report-template.yaml`
+// there rather than next to this file.
+
+use vivafolio_data::params;
+use vivafolio_data::report::RenderFormat;
+use vivafolio_data_macros::{vivafolio_data, vivafolio_report};
+
+vivafolio_data!("project_tasks", r#"
+Task Name,Assignee,Status,Priority
+Implement authentication,Alice,In Progress,High
+Design database schema,Bob,Completed,Medium
+Write API documentation,Charlie,Not Started,Low
+"#);
+
+vivafolio_report!("fixtures/report-template.yaml");
+
+// Regular code continues below...
+fn main() {
+    let table = project_tasks().expect("project_tasks table parses");
+
+    // Parameters referenced by $P{...} in the template are supplied at render time.
+    let params = params! {
+        "company_name" => "Vivafolio Inc.",
+        "average_priority" => "Medium",
+    };
+
+    let report = render_report_template(&table, &params, RenderFormat::Text).expect("report renders");
+    println!("{report}");
+}
@@ -1,6 +1,8 @@
 // Example demonstrating vivafolio_data!() construct for task management
 // This file shows how to embed table-like data directly in source code
 
+use vivafolio_data_macros::vivafolio_data;
+
 vivafolio_data!("project_tasks", r#"
 Task Name,Assignee,Status,Priority,Due Date
 Implement authentication,Alice,In Progress,High,2025-09-20
@@ -21,5 +23,9 @@ Diana,QA Engineer,Testing,2023-11-05
 
 // Regular code continues below...
 fn main() {
-    println!("Hello, Vivafolio!");
+    let tasks = project_tasks().expect("project_tasks table parses");
+    let team = team_members().expect("team_members table parses");
+
+    println!("project_tasks: {} rows, columns {:?}", tasks.rows.len(), tasks.columns);
+    println!("team_members: {} rows, columns {:?}", team.rows.len(), team.columns);
 }